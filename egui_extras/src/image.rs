@@ -1,6 +1,9 @@
 use egui::mutex::Mutex;
 use egui::TextureFilter;
 
+#[cfg(feature = "svg")]
+pub use usvg::FitTo;
+
 /// An image to be shown in egui.
 ///
 /// Load once, and save somewhere in your app state.
@@ -56,6 +59,25 @@ impl RetainedImage {
         ))
     }
 
+    /// Pass in the bytes of an SVG that you've loaded
+    /// and the [`FitTo`] mode to rasterize it with.
+    ///
+    /// See [`load_svg_bytes_with_size`] for how each mode determines the image size.
+    ///
+    /// # Errors
+    /// On invalid image
+    #[cfg(feature = "svg")]
+    pub fn from_svg_bytes_with_size(
+        debug_name: impl Into<String>,
+        svg_bytes: &[u8],
+        fit_to: FitTo,
+    ) -> Result<Self, String> {
+        Ok(Self::from_color_image(
+            debug_name,
+            load_svg_bytes_with_size(svg_bytes, fit_to)?,
+        ))
+    }
+
     /// Pass in the str of an SVG that you've loaded.
     ///
     /// # Errors
@@ -180,12 +202,35 @@ pub fn load_image_bytes(image_bytes: &[u8]) -> Result<egui::ColorImage, String>
 /// On invalid image
 #[cfg(feature = "svg")]
 pub fn load_svg_bytes(svg_bytes: &[u8]) -> Result<egui::ColorImage, String> {
+    load_svg_bytes_with_size(svg_bytes, FitTo::Original)
+}
+
+/// Load an SVG and rasterize it into an egui image using the given [`FitTo`] mode.
+///
+/// The image size is computed the same way `resvg` computes its render size.
+/// `FitTo::Width` and `FitTo::Height` keep the aspect ratio.
+/// `FitTo::Size(w, h)` also keeps the aspect ratio and does not stretch: the SVG
+/// is scaled to fit inside `w`×`h`, and the returned image has that fitted size,
+/// so one side may be smaller than requested.
+///
+/// Requires the "svg" feature.
+///
+/// # Errors
+/// On invalid image
+#[cfg(feature = "svg")]
+pub fn load_svg_bytes_with_size(
+    svg_bytes: &[u8],
+    fit_to: FitTo,
+) -> Result<egui::ColorImage, String> {
     let mut opt = usvg::Options::default();
     opt.fontdb.load_system_fonts();
 
     let rtree = usvg::Tree::from_data(svg_bytes, &opt.to_ref()).map_err(|err| err.to_string())?;
 
-    let pixmap_size = rtree.svg_node().size.to_screen_size();
+    // Use the same size computation as `resvg::render`, so the pixmap matches what gets drawn.
+    let pixmap_size = fit_to
+        .fit_to(rtree.svg_node().size.to_screen_size())
+        .ok_or_else(|| "Invalid SVG target size".to_owned())?;
     let [w, h] = [pixmap_size.width(), pixmap_size.height()];
 
     let mut pixmap = tiny_skia::Pixmap::new(w, h)
@@ -193,7 +238,7 @@ pub fn load_svg_bytes(svg_bytes: &[u8]) -> Result<egui::ColorImage, String> {
 
     resvg::render(
         &rtree,
-        usvg::FitTo::Original,
+        fit_to,
         tiny_skia::Transform::default(),
         pixmap.as_mut(),
     )
@@ -206,3 +251,32 @@ pub fn load_svg_bytes(svg_bytes: &[u8]) -> Result<egui::ColorImage, String> {
 
     Ok(image)
 }
+
+#[cfg(all(test, feature = "svg"))]
+mod test {
+    use super::{load_svg_bytes_with_size, FitTo};
+
+    const SVG: &str = r#"<svg xmlns="http://www.w3.org/2000/svg" width="100" height="50"><rect width="100" height="50" fill="red"/></svg>"#;
+
+    #[test]
+    fn svg_fit_to_sizes() {
+        let size_of = |fit_to| {
+            load_svg_bytes_with_size(SVG.as_bytes(), fit_to)
+                .unwrap()
+                .size
+        };
+
+        assert_eq!(size_of(FitTo::Original), [100, 50]);
+        assert_eq!(size_of(FitTo::Width(200)), [200, 100]);
+        assert_eq!(size_of(FitTo::Height(100)), [200, 100]);
+        assert_eq!(size_of(FitTo::Zoom(2.0)), [200, 100]);
+        // Keeps the aspect ratio instead of stretching to the requested size:
+        assert_eq!(size_of(FitTo::Size(200, 200)), [200, 100]);
+    }
+
+    #[test]
+    fn svg_zero_size_is_err() {
+        assert!(load_svg_bytes_with_size(SVG.as_bytes(), FitTo::Size(0, 0)).is_err());
+        assert!(load_svg_bytes_with_size(SVG.as_bytes(), FitTo::Width(0)).is_err());
+    }
+}